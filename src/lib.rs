@@ -76,11 +76,50 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(all(not(any(feature = "std", test)), feature = "core_error"), feature(error_in_core))]
 
+#[cfg(not(feature = "std"))]
+extern crate alloc as alloc_crate;
+
+/// Compile-time validation of feature combinations.
+///
+/// `cfg_if` arms fail deep inside the facaded crates when features are
+/// combined incorrectly, producing a cascade of confusing trait-resolution
+/// errors. This module turns the known-bad combinations into a single,
+/// actionable [`compile_error!`] naming the conflicting features and the fix.
+///
+/// ### Note
+/// `core_error` requiring a nightly toolchain (for `error_in_core`) is not
+/// checked here: a cargo feature cannot observe the toolchain channel, so
+/// there is no `cfg` that could catch it. That requirement is documented on
+/// the [`thiserror`] facade instead; pick `thiserror_v2` if a stable
+/// toolchain is required.
+mod checks {
+    #[cfg(all(feature = "std", feature = "core_error"))]
+    compile_error!(
+        "platform-switch: features `std` and `core_error` are mutually exclusive \u{2014} `core_error` re-exports `core::error::Error` for no_std targets, which `std` already provides. Enable only one."
+    );
+
+    #[cfg(all(feature = "defmt", not(feature = "log")))]
+    compile_error!(
+        "platform-switch: feature `defmt` requires the `log` feature to be enabled \u{2014} the `log` facade module is what selects between `log` and `defmt` backends. Enable `platform-switch/log` alongside `defmt`."
+    );
+
+    #[cfg(all(feature = "core_error", feature = "thiserror_v2"))]
+    compile_error!(
+        "platform-switch: features `core_error` and `thiserror_v2` are mutually exclusive \u{2014} both provide a no_std `thiserror` derive, `core_error` via the nightly-only `thiserror-core` and `thiserror_v2` via stable `thiserror` 2.x. Pick one no_std story."
+    );
+
+    #[cfg(all(feature = "thiserror_v2", not(feature = "thiserror")))]
+    compile_error!(
+        "platform-switch: feature `thiserror_v2` requires the `thiserror` feature to be enabled \u{2014} the `thiserror` facade module it selects a backend for is gated behind `thiserror`. Enable `platform-switch/thiserror` alongside `thiserror_v2`."
+    );
+}
+
 /// A namespace facade around [`thiserror`].
 ///
 /// Enables [`thiserror`] to be used in both [`std`] and `no_std` environments.
 /// If configured for `no_std`, this module will use `thiserror-core` (which requires
-/// a nightly toolchain).
+/// a nightly toolchain) unless the `thiserror_v2` feature is enabled, in which case
+/// it uses `thiserror` 2.x's own stable-toolchain `no_std` support instead.
 ///
 /// ### Note
 /// This module will be marked deprecated once `error_in_core` is
@@ -89,10 +128,12 @@
 #[cfg(feature = "thiserror")]
 pub mod thiserror {
     cfg_if::cfg_if! {
-        if #[cfg(not(feature = "core_error"))] {
-            pub use thiserror::*;
-        } else {
+        if #[cfg(feature = "core_error")] {
             pub use thiserror_core::*;
+        } else if #[cfg(feature = "thiserror_v2")] {
+            pub use thiserror_v2::*;
+        } else {
+            pub use thiserror::*;
         }
     }
 }
@@ -120,6 +161,48 @@ pub mod log {
     }
 }
 
+/// A namespace facade around the `Error` trait.
+///
+/// Lets downstream code name `platform_switch::error::Error` in trait bounds
+/// and `Box<dyn platform_switch::error::Error>` return types that compile
+/// unchanged on both [`std`] and `no_std` targets, mirroring the role the
+/// [`fmt`] module plays for [`Debug`](fmt::Debug)/[`Display`](fmt::Display).
+///
+/// On `no_std`, `Error` is only re-exported when `core_error` or
+/// `thiserror_v2` is enabled; without either, this module is empty.
+pub mod error {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "std")] {
+            pub use std::error::Error as Error;
+        } else if #[cfg(any(feature = "core_error", feature = "thiserror_v2"))] {
+            pub use core::error::Error as Error;
+        }
+    }
+}
+
+/// A namespace facade around heap-allocated collection and smart-pointer types.
+///
+/// Enables `Vec`, `String`, `Box`, and `Cow` to be used in both [`std`] and
+/// `no_std` environments. If configured for `no_std`, this module re-exports
+/// from the `alloc` crate instead, so a `#[derive(thiserror::Error)]` enum
+/// carrying a `String` payload compiles unchanged on both targets. Extends
+/// the same facade pattern as [`fmt`], [`log`], and [`thiserror`].
+pub mod alloc {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "std")] {
+            pub use std::borrow::Cow as Cow;
+            pub use std::boxed::Box as Box;
+            pub use std::string::String as String;
+            pub use std::vec::Vec as Vec;
+        } else {
+            pub use alloc_crate::borrow::Cow as Cow;
+            pub use alloc_crate::boxed::Box as Box;
+            pub use alloc_crate::string::String as String;
+            pub use alloc_crate::vec::Vec as Vec;
+        }
+    }
+}
+
 /// A namespace facade for formatting.
 pub mod fmt {
     cfg_if::cfg_if! {
@@ -135,4 +218,38 @@ pub mod fmt {
             pub use core::fmt::Result as Result;
         }
     }
+
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "defmt")] {
+            pub use defmt::Debug2Format as Debug2Format;
+            pub use defmt::Display2Format as Display2Format;
+        } else {
+            /// Adapter that formats a [`Debug`] value.
+            ///
+            /// Mirrors `defmt::Debug2Format`'s signature so a single call site
+            /// (e.g. `log::error!("{:?}", fmt::Debug2Format(&err))`) compiles against
+            /// both the `log` and `defmt` backends of the [`log`](crate::log) facade.
+            /// When `defmt` is disabled this is a transparent wrapper that forwards
+            /// to [`core::fmt::Debug`].
+            pub struct Debug2Format<'a, T: ?Sized>(pub &'a T);
+
+            impl<T: ?Sized + Debug> Debug for Debug2Format<'_, T> {
+                fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+                    Debug::fmt(self.0, f)
+                }
+            }
+
+            /// Adapter that formats a [`Display`] value.
+            ///
+            /// See [`Debug2Format`] for why this exists. When `defmt` is disabled
+            /// this is a transparent wrapper that forwards to [`core::fmt::Display`].
+            pub struct Display2Format<'a, T: ?Sized>(pub &'a T);
+
+            impl<T: ?Sized + Display> Display for Display2Format<'_, T> {
+                fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+                    Display::fmt(self.0, f)
+                }
+            }
+        }
+    }
 }